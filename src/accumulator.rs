@@ -0,0 +1,115 @@
+use crate::{FixedTweener, Tween};
+
+/// The result of one [Accumulator::update] call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tick<Value> {
+    /// How many fixed steps were taken this call. `0` if not enough real
+    /// time had accumulated yet to cross a single tick.
+    pub steps: u32,
+    /// The value produced by the last step taken, or `None` if `steps` is
+    /// `0` and the tween hasn't produced a value yet.
+    pub value: Option<Value>,
+    /// The leftover fraction of a tick still sitting in the accumulator,
+    /// in `[0.0, 1.0)`. Useful for interpolating a render-time value
+    /// between the last two ticks instead of snapping to the last one.
+    pub blend_factor: f64,
+}
+
+/// Drives a [FixedTweener] at a deterministic fixed timestep, fed by
+/// irregular real-time deltas from a variable frame loop.
+///
+/// This is the classic "fix your timestep" accumulator: real deltas pile up
+/// in a float accumulator, and the fixed tween is stepped forward once per
+/// `fixed_dt` worth of accumulated time, however many (or few) of those fit
+/// in the real delta just received.
+///
+/// ```ignore
+/// # use tween::{Accumulator, FixedTweener, Linear};
+/// let mut accumulator = Accumulator::new(FixedTweener::new(Linear::new(0.0, 10.0, 1.0), 1.0 / 60.0), 5);
+///
+/// // called once per rendered frame, with however long that frame took:
+/// let tick = accumulator.update(0.031);
+/// ```
+pub struct Accumulator<T>
+where
+    T: Tween<Time = f64>,
+{
+    tweener: FixedTweener<T>,
+    accumulated: f64,
+    max_steps_per_update: u32,
+    last_value: Option<T::Value>,
+}
+
+impl<T> Accumulator<T>
+where
+    T: Tween<Time = f64>,
+{
+    /// Wraps `tweener`, ticking it in steps of its own fixed `delta`.
+    ///
+    /// `max_steps_per_update` caps how many ticks a single [Accumulator::update]
+    /// call will ever take, so a long stall (a debugger pause, a slow disk
+    /// load) can't force a "spiral of death" of ever-more catch-up steps.
+    /// Any time beyond that cap is simply dropped from the accumulator.
+    pub fn new(tweener: FixedTweener<T>, max_steps_per_update: u32) -> Self {
+        Self {
+            tweener,
+            accumulated: 0.0,
+            max_steps_per_update,
+            last_value: None,
+        }
+    }
+
+    /// Allows inspection of the wrapped [FixedTweener].
+    pub fn tweener(&self) -> &FixedTweener<T> {
+        &self.tweener
+    }
+
+    /// Adds `real_delta` seconds to the accumulator, then steps the wrapped
+    /// tween forward once per `delta` worth of accumulated time, up to
+    /// `max_steps_per_update` steps.
+    ///
+    /// If the tween completes partway through the catch-up loop, stepping
+    /// stops immediately and the final value is reported as the last step.
+    pub fn update(&mut self, real_delta: f64) -> Tick<T::Value> {
+        self.accumulated += real_delta;
+
+        let fixed_dt = self.tweener.delta();
+        let mut steps = 0;
+
+        while fixed_dt > 0.0 && self.accumulated >= fixed_dt && steps < self.max_steps_per_update {
+            match self.tweener.next() {
+                Some(v) => {
+                    self.last_value = Some(v);
+                    self.accumulated -= fixed_dt;
+                    steps += 1;
+                }
+                None => {
+                    // The tween finished mid-loop: nothing left to catch up
+                    // on, so stop draining the accumulator too.
+                    self.accumulated = 0.0;
+                    break;
+                }
+            }
+        }
+
+        // We hit the step cap with time still owed: a long stall outpaced
+        // even max_steps_per_update worth of catch-up. Drop the rest rather
+        // than carrying it into the next update, or every following frame
+        // would keep running at the cap until the backlog finally drained.
+        if steps == self.max_steps_per_update && self.accumulated >= fixed_dt {
+            self.accumulated = 0.0;
+        }
+
+        let blend_factor = if fixed_dt > 0.0 {
+            (self.accumulated / fixed_dt).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        Tick {
+            steps,
+            value: self.last_value,
+            blend_factor,
+        }
+    }
+}
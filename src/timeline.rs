@@ -0,0 +1,323 @@
+//! A [Timeline] schedules tweens, delays, and callbacks on a single playhead,
+//! for the cases a bare [Chain](crate::Chain) can't express: "wait, then fire
+//! a callback, then run two tweens in parallel". It follows the shape of
+//! Godot's `SceneTreeTween` (`PropertyTweener` / `IntervalTweener` /
+//! `CallbackTweener`, stepped either in sequence or in parallel).
+//!
+//! Drive a [Timeline] with [Timeline::update] in a variadic loop, or wrap it
+//! in a [FixedTimeline] to drive it with [Iterator] in a fixed-timestep loop,
+//! the same pair of interfaces [Tweener](crate::Tweener) and
+//! [FixedTweener](crate::FixedTweener) offer for a single tween.
+
+use crate::{Tween, TweenTime, TweenValue};
+use std::boxed::Box;
+use std::vec::Vec;
+
+/// One scheduled unit of work on a [Timeline].
+enum Step<Value, Time> {
+    /// A single tween, run until its own `duration` elapses.
+    Tween(Box<dyn Tween<Time = Time, Value = Value>>),
+    /// Several tweens started together; the step lasts as long as the
+    /// longest of them.
+    Parallel(Vec<Box<dyn Tween<Time = Time, Value = Value>>>),
+    /// Pure dead time: no value is produced, but the playhead still has to
+    /// cross it.
+    Interval,
+    /// Fired exactly once, the instant the playhead reaches it.
+    Callback(Box<dyn FnMut()>),
+}
+
+/// A step plus the bookkeeping needed to know when the playhead is inside it.
+struct Entry<Value, Time> {
+    start: Time,
+    duration: Time,
+    step: Step<Value, Time>,
+    fired: bool,
+}
+
+/// Sequences tweens, delays, and callbacks on one playhead.
+///
+/// Build a [Timeline] with [Timeline::new] and the `.then`/`.wait`/`.call`/
+/// `.parallel` builder methods, then drive it the same way as any other
+/// tweener, with [Timeline::update]:
+///
+/// ```ignore
+/// # use tween::{Timeline, Linear};
+/// let mut timeline = Timeline::new()
+///     .wait(30)
+///     .call(|| println!("go!"))
+///     .then(Box::new(Linear::new(0, 10, 10)));
+///
+/// while !timeline.is_finished() {
+///     // `None` while the leading `wait` is active, `Some` once the tween runs
+///     if let Some(value) = timeline.update(1) {
+///         // ...
+///     }
+/// }
+/// ```
+pub struct Timeline<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    entries: Vec<Entry<Value, Time>>,
+    playhead: Time,
+    total_duration: Time,
+    fused: bool,
+}
+
+impl<Value, Time> Timeline<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    /// Creates an empty [Timeline].
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            playhead: Time::ZERO,
+            total_duration: Time::ZERO,
+            fused: false,
+        }
+    }
+
+    /// Appends a tween, run to completion before the next step starts.
+    pub fn then(mut self, tween: Box<dyn Tween<Time = Time, Value = Value>>) -> Self {
+        let duration = tween.duration();
+        self.push(duration, Step::Tween(tween));
+        self
+    }
+
+    /// Appends pure dead time: the playhead still has to cross `duration`,
+    /// but no value is produced while it does.
+    pub fn wait(mut self, duration: Time) -> Self {
+        self.push(duration, Step::Interval);
+        self
+    }
+
+    /// Appends a callback, fired exactly once when the playhead reaches it.
+    pub fn call<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut() + 'static,
+    {
+        self.push(Time::ZERO, Step::Callback(Box::new(callback)));
+        self
+    }
+
+    /// Appends several tweens that all start together; the step lasts as
+    /// long as the longest of them.
+    pub fn parallel(mut self, tweens: Vec<Box<dyn Tween<Time = Time, Value = Value>>>) -> Self {
+        let duration = tweens
+            .iter()
+            .map(|tween| tween.duration())
+            .max_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal))
+            .unwrap_or(Time::ZERO);
+        self.push(duration, Step::Parallel(tweens));
+        self
+    }
+
+    fn push(&mut self, duration: Time, step: Step<Value, Time>) {
+        let start = self.total_duration;
+        self.total_duration = self.total_duration.add(duration);
+        self.entries.push(Entry {
+            start,
+            duration,
+            step,
+            fired: false,
+        });
+    }
+
+    /// The total duration of the timeline: the point at which the longest
+    /// chain of sequential and parallel steps finishes.
+    pub fn duration(&self) -> Time {
+        self.total_duration
+    }
+
+    /// Whether the playhead has reached the end of the timeline.
+    pub fn is_finished(&self) -> bool {
+        self.fused
+    }
+
+    /// Advances the playhead by `delta`, firing any callbacks it crosses and
+    /// returning the value of whichever tween step is active at the new
+    /// position (the last one evaluated, if more than one step is active in
+    /// parallel). Returns `None` while no tween step is active (eg during a
+    /// `wait`), and on every call once the timeline is finished.
+    pub fn update(&mut self, delta: Time) -> Option<Value> {
+        if self.fused {
+            return None;
+        }
+
+        self.playhead = self.playhead.add(delta);
+        if self.playhead.is_complete(self.total_duration) {
+            self.playhead = self.total_duration;
+            self.fused = true;
+        }
+
+        let playhead = self.playhead;
+        let mut value = None;
+
+        for entry in self.entries.iter_mut() {
+            let end = entry.start.add(entry.duration);
+            let reached = playhead.is_complete(entry.start) || playhead == entry.start;
+            // A step stays active through and including its own end instant
+            // (is_complete is `>=`, so `playhead == end` alone doesn't mean
+            // "past end") - only handed off once the playhead strictly
+            // passes it, which also happens to keep the last step's value
+            // visible once the timeline finishes at its end.
+            let past_end = playhead.is_complete(end) && playhead != end;
+            let active = reached && !past_end;
+
+            match &mut entry.step {
+                Step::Callback(callback) => {
+                    if reached && !entry.fired {
+                        callback();
+                        entry.fired = true;
+                    }
+                }
+                Step::Interval => {}
+                Step::Tween(tween) => {
+                    if active {
+                        let local = clamp(playhead.sub(entry.start), entry.duration);
+                        value = Some(if local.is_complete(entry.duration) {
+                            tween.final_value()
+                        } else {
+                            tween.run(local)
+                        });
+                    }
+                }
+                Step::Parallel(tweens) => {
+                    if active {
+                        let local = clamp(playhead.sub(entry.start), entry.duration);
+                        for tween in tweens.iter_mut() {
+                            let local = clamp(local, tween.duration());
+                            value = Some(if local.is_complete(tween.duration()) {
+                                tween.final_value()
+                            } else {
+                                tween.run(local)
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        value
+    }
+}
+
+impl<Value, Time> Default for Timeline<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn clamp<Time: TweenTime>(time: Time, duration: Time) -> Time {
+    if time.is_complete(duration) {
+        duration
+    } else {
+        time
+    }
+}
+
+/// Drives a [Timeline] with a fixed delta per tick, so it can sit in a
+/// fixed-timestep loop as an [Iterator] instead of passing the same delta to
+/// [Timeline::update] on every call - the same relationship
+/// [FixedTweener](crate::FixedTweener) has to [Tweener](crate::Tweener).
+///
+/// Unlike `FixedTweener`, this wraps a [Timeline] rather than re-deriving its
+/// fields: a timeline's entries own `Box<dyn Tween>`/`Box<dyn FnMut>` steps,
+/// so duplicating them isn't an option, and delegating to
+/// [Timeline::update] keeps the playhead-advance logic in exactly one place
+/// rather than a second copy that could drift from it.
+pub struct FixedTimeline<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    timeline: Timeline<Value, Time>,
+    delta: Time,
+}
+
+impl<Value, Time> FixedTimeline<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    /// Wraps `timeline`, advancing it by `delta` on every [Iterator::next] call.
+    pub fn new(timeline: Timeline<Value, Time>, delta: Time) -> Self {
+        Self { timeline, delta }
+    }
+
+    /// Allows inspection of the wrapped [Timeline].
+    pub fn timeline(&self) -> &Timeline<Value, Time> {
+        &self.timeline
+    }
+
+    /// The fixed delta applied per tick.
+    pub fn delta(&self) -> Time {
+        self.delta
+    }
+
+    /// Whether the playhead has reached the end of the timeline.
+    pub fn is_finished(&self) -> bool {
+        self.timeline.is_finished()
+    }
+}
+
+impl<Value, Time> Iterator for FixedTimeline<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.timeline.update(self.delta)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[test]
+    fn wait_after_a_tween_emits_no_value() {
+        let mut timeline = Timeline::new()
+            .then(Box::new(Linear::new(0, 10, 10)))
+            .wait(5);
+
+        assert_eq!(timeline.update(10), Some(10));
+        // Once the playhead has moved on to the trailing `wait`, the
+        // finished tween shouldn't keep reporting its final value.
+        assert_eq!(timeline.update(1), None);
+    }
+
+    #[test]
+    fn last_step_keeps_its_value_once_the_timeline_finishes() {
+        let mut timeline = Timeline::new().then(Box::new(Linear::new(0, 10, 10)));
+
+        assert_eq!(timeline.update(10), Some(10));
+        assert!(timeline.is_finished());
+    }
+
+    #[test]
+    fn fixed_timeline_advances_by_its_own_delta_each_next_call() {
+        let timeline = Timeline::new().then(Box::new(Linear::new(0, 10, 10)));
+        let mut fixed_timeline = FixedTimeline::new(timeline, 2);
+
+        assert_eq!(fixed_timeline.next(), Some(2));
+        assert_eq!(fixed_timeline.next(), Some(4));
+        assert!(!fixed_timeline.is_finished());
+
+        let tail: Vec<_> = fixed_timeline.by_ref().take(3).collect();
+        assert_eq!(tail, vec![6, 8, 10]);
+        assert!(fixed_timeline.is_finished());
+    }
+}
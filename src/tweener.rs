@@ -34,6 +34,7 @@ pub struct Tweener<T: Tween> {
     tween: T,
     last_time: T::Time,
     fused: bool,
+    speed: Speed,
 }
 
 impl<T> Tweener<T>
@@ -46,6 +47,7 @@ where
             tween,
             last_time: T::Time::ZERO,
             fused: false,
+            speed: Speed::default(),
         }
     }
 
@@ -53,29 +55,141 @@ where
     ///
     /// If an input higher than the tween's `duration` is given, you will
     /// receive the max value of the tween.
+    ///
+    /// If the speed has been set to a negative value via [Tweener::set_speed],
+    /// `delta` instead plays the tween *backwards*: `last_time` moves towards
+    /// zero rather than `duration`.
     pub fn update(&mut self, delta: T::Time) -> Option<T::Value> {
-        if !self.fused {
-            self.last_time = self.last_time.add(delta);
-
-            let output = if self.last_time.is_complete(self.tween.duration()) {
-                self.fused = true;
-                self.last_time = self.tween.duration();
-
-                self.tween.final_value()
+        if self.fused {
+            if self.is_reversing_off_boundary() {
+                self.fused = false;
             } else {
-                self.tween.run(self.last_time)
-            };
+                return None;
+            }
+        }
 
-            Some(output)
+        let scaled_delta = delta.scale(self.speed.0.abs());
+        self.last_time = if self.speed.0 >= 0.0 {
+            self.last_time.add(scaled_delta)
         } else {
-            None
-        }
+            saturating_sub_to_zero(self.last_time, scaled_delta)
+        };
+
+        Some(self.settle())
+    }
+
+    /// Jumps straight to an absolute point in time and re-evaluates the
+    /// tween there, without needing to replay every `update` in between.
+    ///
+    /// This is clamped to `[T::Time::ZERO, duration]`, just like `update`.
+    pub fn seek(&mut self, time: T::Time) -> T::Value {
+        self.last_time = time;
+        self.settle()
+    }
+
+    /// Whether the tween has reached the end of its travel: `duration` if
+    /// playing forwards, or zero if playing backwards (speed < 0.0).
+    pub fn is_finished(&self) -> bool {
+        self.fused
+    }
+
+    /// Sets the speed the tween is played back at. `1.0` is normal speed,
+    /// `2.0` is double speed, and a negative speed (eg `-1.0`) plays the
+    /// tween in reverse.
+    ///
+    /// Reversing direction away from a boundary clears a tween which had
+    /// finished, letting it animate back out.
+    pub fn set_speed(&mut self, speed: f64) {
+        self.speed = Speed(speed);
+    }
+
+    /// The current playback speed. See [Tweener::set_speed].
+    pub fn speed(&self) -> f64 {
+        self.speed.0
+    }
+
+    /// Mutable access to the wrapped tween, eg to replace it with a different
+    /// curve mid-animation. The current time and speed are kept.
+    pub fn tween_mut(&mut self) -> &mut T {
+        &mut self.tween
     }
 
     /// Converts this tweener to a [Looper].
     pub fn looper(self) -> Looper<T> {
         Looper::new(self)
     }
+
+    /// Whether the current playback direction would move `last_time` away
+    /// from the boundary it's currently fused at, rather than further into
+    /// it. A tween fused at zero (from playing backwards) un-fuses once
+    /// speed turns positive again, and one fused at `duration` un-fuses once
+    /// speed turns negative.
+    fn is_reversing_off_boundary(&self) -> bool {
+        if self.last_time == T::Time::ZERO {
+            self.speed.0 > 0.0
+        } else {
+            self.speed.0 < 0.0
+        }
+    }
+
+    /// Clamps `last_time` into `[ZERO, duration]`, updates `fused` to match
+    /// the boundary we landed on (if any) and the current direction of
+    /// travel, and evaluates the tween at the resulting time.
+    fn settle(&mut self) -> T::Value {
+        let duration = self.tween.duration();
+
+        if self.last_time.is_complete(duration) {
+            self.last_time = duration;
+            self.fused = self.speed.0 >= 0.0;
+            self.tween.final_value()
+        } else if self.last_time <= T::Time::ZERO {
+            self.last_time = T::Time::ZERO;
+            self.fused = self.speed.0 < 0.0;
+            self.tween.run(self.last_time)
+        } else {
+            self.fused = false;
+            self.tween.run(self.last_time)
+        }
+    }
+}
+
+/// A playback speed multiplier for [Tweener] and [FixedTweener].
+///
+/// This wraps a plain `f64` so the tweeners can keep deriving
+/// `Eq`/`Ord`/`Hash`, which floats don't implement; `Speed` hand-rolls
+/// bitwise equality instead, which is good enough for a value that's only
+/// ever set wholesale via [Tweener::set_speed].
+#[derive(Debug, Clone, Copy)]
+struct Speed(f64);
+
+impl Default for Speed {
+    fn default() -> Self {
+        Speed(1.0)
+    }
+}
+
+impl PartialEq for Speed {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+impl Eq for Speed {}
+
+impl PartialOrd for Speed {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Speed {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl core::hash::Hash for Speed {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
 }
 
 /// A FixedTweener "drives" a tween for you, allowing you provide *deltas*
@@ -104,6 +218,7 @@ pub struct FixedTweener<T: Tween> {
     last_time: T::Time,
     delta: T::Time,
     fused: bool,
+    speed: Speed,
 }
 
 impl<T> FixedTweener<T>
@@ -118,6 +233,7 @@ where
             last_time: T::Time::ZERO,
             delta,
             fused: false,
+            speed: Speed::default(),
         }
     }
 
@@ -131,6 +247,65 @@ where
         self.last_time
     }
 
+    /// The fixed delta time this tweener advances by on every tick.
+    pub fn delta(&self) -> T::Time {
+        self.delta
+    }
+
+    /// Jumps straight to an absolute point in time and re-evaluates the
+    /// tween there. Clamped to `[T::Time::ZERO, duration]`.
+    pub fn seek(&mut self, time: T::Time) -> T::Value {
+        self.last_time = time;
+        self.settle()
+    }
+
+    /// Whether the tween has reached the end of its travel: `duration` if
+    /// playing forwards, or zero if playing backwards (speed < 0.0).
+    pub fn is_finished(&self) -> bool {
+        self.fused
+    }
+
+    /// Sets the speed ticks are played back at. `1.0` is normal speed, and a
+    /// negative speed (eg `-1.0`) plays the tween in reverse.
+    pub fn set_speed(&mut self, speed: f64) {
+        self.speed = Speed(speed);
+    }
+
+    /// The current playback speed. See [FixedTweener::set_speed].
+    pub fn speed(&self) -> f64 {
+        self.speed.0
+    }
+
+    /// Whether the current playback direction would move `last_time` away
+    /// from the boundary it's currently fused at, rather than further into
+    /// it. A tween fused at zero (from playing backwards) un-fuses once
+    /// speed turns positive again, and one fused at `duration` un-fuses once
+    /// speed turns negative.
+    fn is_reversing_off_boundary(&self) -> bool {
+        if self.last_time == T::Time::ZERO {
+            self.speed.0 > 0.0
+        } else {
+            self.speed.0 < 0.0
+        }
+    }
+
+    fn settle(&mut self) -> T::Value {
+        let duration = self.tween.duration();
+
+        if self.last_time.is_complete(duration) {
+            self.last_time = duration;
+            self.fused = self.speed.0 >= 0.0;
+            self.tween.final_value()
+        } else if self.last_time <= T::Time::ZERO {
+            self.last_time = T::Time::ZERO;
+            self.fused = self.speed.0 < 0.0;
+            self.tween.run(self.last_time)
+        } else {
+            self.fused = false;
+            self.tween.run(self.last_time)
+        }
+    }
+
     /// Converts this tweener to a [FixedLooper].
     pub fn looper(self) -> FixedLooper<T> {
         FixedLooper::new(self)
@@ -168,18 +343,36 @@ where
     type Item = T::Value;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if !self.fused {
-            self.last_time = self.last_time.add(self.delta);
-
-            if self.last_time.is_complete(self.tween.duration()) {
-                self.fused = true;
-                Some(self.tween.final_value())
+        if self.fused {
+            if self.is_reversing_off_boundary() {
+                self.fused = false;
             } else {
-                Some(self.tween.run(self.last_time))
+                return None;
             }
-        } else {
-            None
         }
+
+        let scaled_delta = self.delta.scale(self.speed.0.abs());
+        self.last_time = if self.speed.0 >= 0.0 {
+            self.last_time.add(scaled_delta)
+        } else {
+            saturating_sub_to_zero(self.last_time, scaled_delta)
+        };
+
+        Some(self.settle())
+    }
+}
+
+/// `current - delta`, saturating at `Time::ZERO` instead of underflowing.
+///
+/// Reverse playback can be asked to step backwards by more time than is left
+/// on the clock (eg a finished tween fed a large reverse delta); a plain
+/// `sub` would panic for an unsigned `Time` like `Duration` in that case, so
+/// we clamp to zero instead of subtracting past it.
+fn saturating_sub_to_zero<Time: TweenTime>(current: Time, delta: Time) -> Time {
+    if delta >= current {
+        Time::ZERO
+    } else {
+        current.sub(delta)
     }
 }
 
@@ -236,6 +429,28 @@ mod tests {
         assert_eq!(oscillator.direction(), OscillationDirection::Falling);
     }
 
+    #[test]
+    fn tweener_reverses_with_negative_speed() {
+        let mut tweener = Tweener::new(Linear::new(0, 10, 10));
+        assert_eq!(tweener.update(10).unwrap(), 10);
+        assert!(tweener.is_finished());
+
+        tweener.set_speed(-1.0);
+        assert_eq!(tweener.update(4).unwrap(), 6);
+        assert!(!tweener.is_finished());
+        assert_eq!(tweener.update(100).unwrap(), 0);
+        assert!(tweener.is_finished());
+    }
+
+    #[test]
+    fn tweener_seek_jumps_to_absolute_time() {
+        let mut tweener = Tweener::new(Linear::new(0, 10, 10));
+        assert_eq!(tweener.seek(3), 3);
+        assert!(!tweener.is_finished());
+        assert_eq!(tweener.seek(10), 10);
+        assert!(tweener.is_finished());
+    }
+
     #[test]
     fn fixed_tweener_oscillator() {
         let mut oscillator: FixedOscillator<Linear<i32, i32>> =
@@ -0,0 +1,192 @@
+use crate::{Tween, TweenTime, TweenValue};
+use std::boxed::Box;
+use std::vec::Vec;
+
+/// One child tween plus the native duration it was created with, kept
+/// around separately so [Chain::with_total_duration] can rescale the whole
+/// sequence without losing track of each child's original proportions.
+struct Link<Value, Time> {
+    tween: Box<dyn Tween<Time = Time, Value = Value>>,
+    native_duration: Time,
+}
+
+/// Concatenates several tweens end-to-end into a single, longer tween.
+///
+/// By default a [Chain]'s `duration` is just the sum of its children's
+/// durations, and `run` maps straight through to whichever child owns the
+/// current time. Use [Chain::with_total_duration] instead when you want to
+/// retime the whole sequence to an exact length while keeping each child's
+/// relative timing intact.
+pub struct Chain<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    links: Vec<Link<Value, Time>>,
+    /// The sum of every child's native (un-rescaled) duration.
+    native_total: Time,
+    /// The total duration requested via [Chain::with_total_duration], if
+    /// any. Kept around so a `then()` call after `with_total_duration()`
+    /// can recompute `scale` instead of leaving it stale.
+    requested_total: Option<Time>,
+    /// `total_duration / native_total`. `1.0` unless
+    /// [Chain::with_total_duration] was used.
+    scale: f64,
+    total_duration: Time,
+}
+
+impl<Value, Time> Chain<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    /// Creates a new, empty [Chain].
+    pub fn new() -> Self {
+        Self {
+            links: Vec::new(),
+            native_total: Time::ZERO,
+            requested_total: None,
+            scale: 1.0,
+            total_duration: Time::ZERO,
+        }
+    }
+
+    /// Appends a tween to the end of the chain.
+    ///
+    /// If [Chain::with_total_duration] was already called, the rescaling is
+    /// recomputed to account for the newly added child, so the two builder
+    /// methods can be mixed in either order.
+    pub fn then(mut self, tween: Box<dyn Tween<Time = Time, Value = Value>>) -> Self {
+        let native_duration = tween.duration();
+        self.native_total = self.native_total.add(native_duration);
+        self.links.push(Link {
+            tween,
+            native_duration,
+        });
+        self.recompute();
+        self
+    }
+
+    /// Rescales every child's time mapping so their native duration *ratios*
+    /// are preserved, but the whole chain spans exactly `total` instead of
+    /// the sum of its children's native durations.
+    ///
+    /// For example, a 1s segment followed by a 2s segment retimed to a 2s
+    /// total still spends a third of the chain on the first segment and two
+    /// thirds on the second — just compressed to fit.
+    pub fn with_total_duration(mut self, total: Time) -> Self {
+        self.requested_total = Some(total);
+        self.recompute();
+        self
+    }
+
+    /// Recomputes `scale` and `total_duration` from `native_total` and
+    /// `requested_total`. Called after every change to either, so the two
+    /// can never fall out of sync regardless of call order.
+    fn recompute(&mut self) {
+        match self.requested_total {
+            Some(total) => {
+                self.scale = if self.native_total.to_f64() > 0.0 {
+                    total.to_f64() / self.native_total.to_f64()
+                } else {
+                    1.0
+                };
+                self.total_duration = total;
+            }
+            None => {
+                self.scale = 1.0;
+                self.total_duration = self.native_total;
+            }
+        }
+    }
+
+    /// The chain's total duration: either the sum of its children's native
+    /// durations, or the value passed to [Chain::with_total_duration].
+    pub fn duration(&self) -> Time {
+        self.total_duration
+    }
+
+    /// Evaluates the chain at global time `t`, mapping it back into
+    /// whichever child owns that point, and that child's own local time.
+    pub fn run(&mut self, t: Time) -> Value {
+        assert!(
+            !self.links.is_empty(),
+            "Chain::run called with no child tweens; add at least one with Chain::then first"
+        );
+
+        let mut elapsed_native = Time::ZERO;
+        let scale = self.scale;
+        let link_count = self.links.len();
+
+        for (index, link) in self.links.iter_mut().enumerate() {
+            let scaled_native = Time::from_f64(link.native_duration.to_f64() * scale);
+            let scaled_start = Time::from_f64(elapsed_native.to_f64() * scale);
+            let scaled_end = scaled_start.add(scaled_native);
+
+            let is_last = index + 1 == link_count;
+            if t.is_complete(scaled_end) && !is_last {
+                elapsed_native = elapsed_native.add(link.native_duration);
+                continue;
+            }
+
+            let local_scaled = Time::from_f64((t.to_f64() - scaled_start.to_f64()).max(0.0));
+            let local_native = if scale > 0.0 {
+                Time::from_f64(local_scaled.to_f64() / scale)
+            } else {
+                Time::ZERO
+            };
+
+            return if local_native.is_complete(link.native_duration) {
+                link.tween.final_value()
+            } else {
+                link.tween.run(local_native)
+            };
+        }
+
+        unreachable!("the loop above always returns for a non-empty chain")
+    }
+}
+
+impl<Value, Time> Default for Chain<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Value, Time> Tween for Chain<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    type Time = Time;
+    type Value = Value;
+
+    fn duration(&self) -> Time {
+        // Resolves to the inherent `Chain::duration` above: inherent methods
+        // always win over trait methods of the same name, so this isn't
+        // the recursive call it looks like.
+        self.duration()
+    }
+
+    fn run(&mut self, new_time: Time) -> Value {
+        // Resolves to the inherent `Chain::run` above, for the same reason.
+        self.run(new_time)
+    }
+
+    fn final_value(&mut self) -> Value {
+        assert!(
+            !self.links.is_empty(),
+            "Chain::final_value called with no child tweens; add at least one with Chain::then first"
+        );
+
+        self.links
+            .last_mut()
+            .expect("checked non-empty above")
+            .tween
+            .final_value()
+    }
+}
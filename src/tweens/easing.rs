@@ -0,0 +1,201 @@
+use crate::{Linear, QuartIn, QuartInOut, QuartOut, Tween, TweenTime, TweenValue};
+use core::fmt;
+use core::str::FromStr;
+
+/// Declares `EasingName` and `Easing`, and every impl that dispatches over
+/// their variants, from a single list of `Variant => "name"` pairs.
+///
+/// Every match arm below is generated from the same list, so `EasingName`,
+/// `Easing::new`, `Easing::name`, and the `Tween` impl can't drift out of
+/// sync with each other the way hand-duplicated matches eventually do. This
+/// macro doesn't hardcode that list itself - see the invocation below, which
+/// builds it by expanding through each curve module's own `declare_tween!`
+/// roster (eg `quart_curves!`) instead of retyping it here.
+macro_rules! declare_easing {
+    ($( $variant:ident => $name:literal ),+ $(,)?) => {
+        /// The shape of an [Easing] curve, without any of the start/end/duration
+        /// state that makes it runnable.
+        ///
+        /// This is mostly useful for naming a curve in data (config files, editor
+        /// dropdowns, save files) before you have the start/end/duration on hand to
+        /// build a real [Easing].
+        #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+        pub enum EasingName {
+            $( $variant ),+
+        }
+
+        impl fmt::Display for EasingName {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let name = match self {
+                    $( EasingName::$variant => $name ),+
+                };
+                f.write_str(name)
+            }
+        }
+
+        impl FromStr for EasingName {
+            type Err = ParseEasingError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $( $name => Ok(EasingName::$variant), )+
+                    _ => Err(ParseEasingError),
+                }
+            }
+        }
+
+        /// A [Tween] whose curve is picked at runtime instead of being fixed by the
+        /// type, so it can be swapped mid-animation or loaded from data.
+        ///
+        /// Every curve `declare_tween!` generates gets a matching variant here, kept
+        /// in lockstep with the concrete types via the `declare_easing!` list above. Because
+        /// this is a `match` over a closed set of variants rather than a
+        /// `Box<dyn Tween>`, a [Tweener](crate::Tweener)`<Easing<Value, Time>>` pays
+        /// no allocation or vtable cost to let its curve change out from under it:
+        ///
+        /// ```ignore
+        /// # use tween::{Tweener, Easing, EasingName};
+        /// let mut tweener = Tweener::new(Easing::new(EasingName::QuartIn, 0, 100, 10));
+        /// // later, reassign to a different curve without recreating the Tweener:
+        /// *tweener.tween_mut() = Easing::new(EasingName::QuartOut, 0, 100, 10);
+        /// ```
+        #[derive(Debug, Clone, Copy)]
+        pub enum Easing<Value, Time>
+        where
+            Value: TweenValue,
+            Time: TweenTime,
+        {
+            $( $variant($variant<Value, Time>) ),+
+        }
+
+        impl<Value, Time> Easing<Value, Time>
+        where
+            Value: TweenValue,
+            Time: TweenTime,
+        {
+            /// Creates a new [Easing] of the given shape, running from `start` to
+            /// `end` over `duration`.
+            pub fn new(name: EasingName, start: Value, end: Value, duration: Time) -> Self {
+                match name {
+                    $( EasingName::$variant => Easing::$variant($variant::new(start, end, duration)), )+
+                }
+            }
+
+            /// The shape of this curve, without its start/end/duration state.
+            pub fn name(&self) -> EasingName {
+                match self {
+                    $( Easing::$variant(_) => EasingName::$variant, )+
+                }
+            }
+        }
+
+        impl<Value, Time> fmt::Display for Easing<Value, Time>
+        where
+            Value: TweenValue,
+            Time: TweenTime,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.name(), f)
+            }
+        }
+
+        impl<Value, Time> FromStr for Easing<Value, Time>
+        where
+            Value: TweenValue + Default,
+            Time: TweenTime + Default,
+        {
+            type Err = ParseEasingError;
+
+            /// Parses a curve by name, starting it at the `Default` of `Value` and
+            /// `Time`. Callers that already know their start/end/duration should
+            /// prefer `Easing::new(s.parse()?, start, end, duration)`.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let name: EasingName = s.parse()?;
+                Ok(Easing::new(name, Value::default(), Value::default(), Time::default()))
+            }
+        }
+
+        impl<Value, Time> Tween for Easing<Value, Time>
+        where
+            Value: TweenValue,
+            Time: TweenTime,
+        {
+            type Time = Time;
+            type Value = Value;
+
+            fn duration(&self) -> Time {
+                match self {
+                    $( Easing::$variant(tween) => tween.duration(), )+
+                }
+            }
+
+            fn run(&mut self, new_time: Time) -> Value {
+                match self {
+                    $( Easing::$variant(tween) => tween.run(new_time), )+
+                }
+            }
+
+            fn final_value(&mut self) -> Value {
+                match self {
+                    $( Easing::$variant(tween) => tween.final_value(), )+
+                }
+            }
+        }
+    };
+}
+
+// Seed the list with curves that don't come from a `declare_tween!` family
+// module of their own (just `Linear`, here), then let `quart_curves!` append
+// its own entries - the exact set `src/tweens/quart.rs`'s `declare_tween!`
+// invocations declare - so this enum can't list a different set of curves
+// than the concrete types actually exist for.
+crate::tweens::quart::quart_curves!(declare_easing! {
+    Linear => "linear",
+});
+
+/// Returned when a string doesn't name a known [EasingName].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseEasingError;
+
+impl fmt::Display for ParseEasingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unrecognized easing name")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEasingError {}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_round_trips_through_display_and_from_str() {
+        for name in [
+            EasingName::Linear,
+            EasingName::QuartIn,
+            EasingName::QuartOut,
+            EasingName::QuartInOut,
+        ] {
+            assert_eq!(name.to_string().parse::<EasingName>().unwrap(), name);
+        }
+    }
+
+    #[test]
+    fn unknown_name_is_an_error() {
+        assert_eq!("wobble".parse::<EasingName>(), Err(ParseEasingError));
+    }
+
+    #[test]
+    fn dispatches_to_the_selected_curve() {
+        let mut linear = Easing::new(EasingName::Linear, 0, 10, 10);
+        let mut quart_in = Easing::new(EasingName::QuartIn, 0, 10, 10);
+
+        assert_eq!(linear.run(5), 5);
+        assert_ne!(quart_in.run(5), 5);
+        assert_eq!(linear.final_value(), 10);
+        assert_eq!(quart_in.final_value(), 10);
+    }
+}
@@ -44,3 +44,27 @@ declare_tween!(
 );
 
 test_tween!(Quart);
+
+/// The curves this module declares, one line per `declare_tween!` above.
+///
+/// `crate::tweens::easing` seeds its curve-name list by expanding through
+/// this macro, so the `Easing` enum is always built from the exact set of
+/// curves this module declares — adding a `declare_tween!` above without a
+/// matching entry here (or vice versa) is the only way they could drift,
+/// and both live in the same file for the same review to catch.
+///
+/// `$callback` is invoked with whatever tokens the caller prefixed, followed
+/// by this module's own `Variant => "name"` entries, so callers can seed the
+/// list with curves from other modules (eg `Linear`) before appending ours.
+macro_rules! quart_curves {
+    ($callback:ident ! { $($prefix:tt)* }) => {
+        $callback! {
+            $($prefix)*
+            QuartIn => "quart_in",
+            QuartOut => "quart_out",
+            QuartInOut => "quart_in_out",
+        }
+    };
+}
+
+pub(crate) use quart_curves;